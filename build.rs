@@ -1,3 +1,6 @@
+#[path = "codegen.rs"]
+mod codegen;
+
 fn main() {
     let target = std::env::var("TARGET").unwrap();
 
@@ -26,4 +29,26 @@ fn main() {
 
     #[cfg(feature = "quicklook")]
     println!("cargo:rustc-link-lib=framework=QuickLook");
+
+    generate_bindings();
+}
+
+/// Behind the `codegen` feature, walks `codegen::MANIFEST` and emits the generated
+/// `selector_export` wrapper boilerplate to `$OUT_DIR/generated_bindings.rs`, which `src/lib.rs`
+/// then `include!`s. A no-op without the feature, so the manifest never affects a default build.
+fn generate_bindings() {
+    if std::env::var_os("CARGO_FEATURE_CODEGEN").is_none() {
+        return;
+    }
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let dest = std::path::Path::new(&out_dir).join("generated_bindings.rs");
+
+    let mut generated = String::new();
+    for class in codegen::MANIFEST {
+        generated.push_str(&codegen::emit(class));
+    }
+
+    std::fs::write(&dest, generated).expect("failed to write generated_bindings.rs");
+    println!("cargo:rerun-if-changed=codegen.rs");
 }
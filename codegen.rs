@@ -0,0 +1,279 @@
+//! Declarative manifest of Foundation classes consumed by `build.rs` to emit `selector_export`
+//! wrapper boilerplate into `OUT_DIR`, for `include!`-ing from `src/lib.rs` behind the `codegen`
+//! feature. The manifest is plain Rust data rather than a separate file format (TOML/JSON) so
+//! this doesn't need a manifest parser as a build dependency.
+//!
+//! Adding a class here gets you the standard `#[repr(transparent)]` wrapper, `Deref`,
+//! `objc::Message`, `alloc`, retain/release `Drop`/`Clone`, and `GetObjcObject` impls, plus one
+//! method per manifest entry — the same boilerplate the hand-written wrappers in `src/lib.rs`
+//! repeat. A method returning a primitive or raw `id` becomes a `selector_export`-annotated
+//! binding; a method returning an object wrapper becomes a hand-written body that retains the
+//! result on ingest (or, for an `init...` instance method, consumes the `alloc`'d `self`).
+
+pub enum MethodKind {
+    /// A class method, e.g. `+[NSProcessInfo processInfo]`.
+    Class,
+    /// An instance method, e.g. `-[NSProcessInfo operatingSystemVersionString]`.
+    Instance,
+}
+
+pub struct MethodSpec {
+    /// The Objective-C selector, e.g. `"stringByAppendingPathComponent:"`. The generated Rust
+    /// method name is derived from this (see `selector_to_rust_name`), not hand-typed.
+    pub selector: &'static str,
+    pub kind: MethodKind,
+    /// Additional arguments beyond the implicit receiver, as (name, Rust type) pairs.
+    pub args: &'static [(&'static str, &'static str)],
+    /// The Rust return type. Use `"id"` for a raw, unwrapped object pointer; anything else must
+    /// name a type that is `objc::Encode` (one of this crate's wrappers, a primitive, etc).
+    pub ret: &'static str,
+}
+
+pub struct ClassSpec {
+    pub name: &'static str,
+    pub methods: &'static [MethodSpec],
+}
+
+/// Selector → return-wrapper overrides, applied when a manifest entry leaves `ret` as `"id"` but
+/// the selector is a well-known one that actually hands back a richer wrapper type. An explicit
+/// `ret` on the manifest entry itself always wins over this table.
+pub const RETURN_TYPE_OVERRIDES: &[(&str, &str)] = &[
+    ("localizedDescription", "NSString"),
+    ("description", "NSString"),
+    ("debugDescription", "NSString"),
+];
+
+/// The classes to generate bindings for. Extend this array to cover more of Foundation without
+/// hand-writing the wrapper boilerplate.
+pub const MANIFEST: &[ClassSpec] = &[ClassSpec {
+    name: "NSProcessInfo",
+    methods: &[
+        MethodSpec {
+            selector: "processInfo",
+            kind: MethodKind::Class,
+            args: &[],
+            ret: "NSProcessInfo",
+        },
+        MethodSpec {
+            selector: "operatingSystemVersionString",
+            kind: MethodKind::Instance,
+            args: &[],
+            ret: "NSString",
+        },
+        MethodSpec {
+            selector: "processName",
+            kind: MethodKind::Instance,
+            args: &[],
+            ret: "NSString",
+        },
+    ],
+}];
+
+/// Translates a single camelCase selector fragment (the text between colons, or the whole
+/// selector if it takes no arguments) into its snake_case equivalent, splitting at each
+/// lower-to-upper transition and at the trailing edge of an acronym run (e.g. `"URLFor"` ->
+/// `"url_for"`).
+fn fragment_to_snake_case(fragment: &str) -> String {
+    let chars: Vec<char> = fragment.chars().collect();
+    let mut words = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev_is_lower = i > 0 && chars[i - 1].is_lowercase();
+            let next_is_lower = i + 1 < chars.len() && chars[i + 1].is_lowercase();
+            if !current.is_empty() && (prev_is_lower || next_is_lower) {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+        current.extend(c.to_lowercase());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words.join("_")
+}
+
+/// Translates an Objective-C selector into its Rust method name, e.g.
+/// `"stringByAppendingPathComponent:"` -> `"string_by_appending_path_component"` and
+/// `"initWithBytes:length:"` -> `"init_with_bytes_length"`.
+fn selector_to_rust_name(selector: &str) -> String {
+    selector
+        .split(':')
+        .filter(|fragment| !fragment.is_empty())
+        .map(fragment_to_snake_case)
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn resolve_return_type(method: &MethodSpec) -> &'static str {
+    if method.ret != "id" {
+        return method.ret;
+    }
+
+    RETURN_TYPE_OVERRIDES
+        .iter()
+        .find(|(selector, _)| *selector == method.selector)
+        .map(|(_, wrapper)| *wrapper)
+        .unwrap_or("id")
+}
+
+/// Scalar/raw-pointer return types that aren't Objective-C objects, so there's nothing to
+/// retain — these stay plain `#[selector_export]` bindings.
+const PRIMITIVE_RETURN_TYPES: &[&str] = &[
+    "id", "bool", "usize", "f64", "f32", "NSInteger", "NSUInteger", "*const u8", "*const c_void",
+];
+
+/// Selector prefixes that, per Cocoa's memory-management naming convention, hand back an object
+/// the caller already owns (retain count 1) rather than an autoreleased one.
+const OWNING_SELECTOR_PREFIXES: &[&str] = &["alloc", "new", "copy", "mutableCopy", "init"];
+
+/// Whether `method` is a designated initializer, called on an `alloc`'d instance. These need a
+/// different shape than every other owning method: `init...` may return a different pointer than
+/// it was sent to (e.g. a singleton, or `nil` on failure), so the generated method must consume
+/// `self` by value and forget it, the same way the hand-written `init`s in `src/lib.rs` do, rather
+/// than just skipping the retain like an ordinary alloc/new/copy method would.
+fn is_init_method(method: &MethodSpec) -> bool {
+    matches!(method.kind, MethodKind::Instance) && method.selector.starts_with("init")
+}
+
+/// Whether `method` needs its result retained on ingest before it's wrapped in a `Drop`-releasing
+/// type: it does unless it returns a primitive/raw value, or its selector is one of Cocoa's
+/// alloc/new/copy/mutableCopy/init-prefixed "already owned" methods.
+fn needs_retain(method: &MethodSpec, ret: &str) -> bool {
+    if PRIMITIVE_RETURN_TYPES.contains(&ret) {
+        return false;
+    }
+
+    !OWNING_SELECTOR_PREFIXES
+        .iter()
+        .any(|prefix| method.selector.starts_with(prefix))
+}
+
+/// Builds the `msg_send!` argument list for `selector` against `receiver`, zipping each
+/// colon-delimited selector fragment with the entry at the same position in `args`, which is
+/// passed by its name.
+fn msg_send_call(receiver: &str, selector: &str, args: &[(&str, &str)]) -> String {
+    if !selector.contains(':') {
+        return format!("{receiver}, {selector}");
+    }
+
+    let fragments: Vec<&str> = selector.split(':').filter(|f| !f.is_empty()).collect();
+    let parts: Vec<String> = fragments
+        .iter()
+        .zip(args.iter())
+        .map(|(fragment, (arg_name, _))| format!("{fragment}: {arg_name}"))
+        .collect();
+
+    format!("{receiver}, {}", parts.join(" "))
+}
+
+/// Emits the wrapper boilerplate plus one method per manifest entry for `spec` (a
+/// `selector_export` binding for primitive/raw returns, a retaining or `init`-consuming body for
+/// object returns), as a string of Rust source ready to be written into `OUT_DIR` and `include!`-d.
+pub fn emit(spec: &ClassSpec) -> String {
+    let name = spec.name;
+    let mut out = String::new();
+
+    out.push_str(&format!("#[repr(transparent)]\npub struct {name}(pub id);\n"));
+    out.push_str(&format!("impl std::ops::Deref for {name} {{\n"));
+    out.push_str("    type Target = objc::runtime::Object;\n");
+    out.push_str("    fn deref(&self) -> &Self::Target {\n");
+    out.push_str("        unsafe { &*self.0 }\n");
+    out.push_str("    }\n}\n\n");
+
+    out.push_str(&format!("impl Drop for {name} {{\n"));
+    out.push_str("    fn drop(&mut self) {\n        unsafe { objc_release(self.0) };\n    }\n}\n\n");
+
+    out.push_str(&format!("impl Clone for {name} {{\n"));
+    out.push_str("    fn clone(&self) -> Self {\n        Self(unsafe { objc_retain(self.0) })\n    }\n}\n");
+
+    out.push_str(&format!("unsafe impl objc::Message for {name} {{}}\n"));
+    out.push_str(&format!("impl {name} {{\n"));
+    out.push_str(&format!(
+        "    pub fn alloc() -> Self {{\n        Self(unsafe {{ msg_send!(objc::class!({name}), alloc) }})\n    }}\n}}\n\n"
+    ));
+
+    out.push_str(&format!("impl {name} {{\n"));
+
+    for method in spec.methods {
+        let ret = resolve_return_type(method);
+        let rust_name = selector_to_rust_name(method.selector);
+        let extra_args: String = method
+            .args
+            .iter()
+            .map(|(arg_name, arg_type)| format!(", {}: {}", arg_name, arg_type))
+            .collect();
+
+        if is_init_method(method) {
+            // Called on an `alloc`'d instance: consume `self` and forget it rather than
+            // borrowing, so its `Drop` doesn't release the object the returned instance now owns.
+            let call = msg_send_call("self.0", method.selector, method.args);
+
+            out.push_str(&format!(
+                "    /// Called on an `alloc`'d instance. `init...` may return a different pointer than it was sent to, so this consumes `self` and forgets it rather than borrowing, so its `Drop` doesn't release the object the returned instance now owns.\n    pub fn {rust_name}(self{args}) -> {ret} {{\n        let ret = {ret}(unsafe {{ msg_send![{call}] }});\n        mem::forget(self);\n        ret\n    }}\n",
+                rust_name = rust_name,
+                args = extra_args,
+                ret = ret,
+                call = call,
+            ));
+            continue;
+        }
+
+        if needs_retain(method, ret) {
+            // Autoreleased result: retain on ingest so its lifetime is tied to the returned
+            // wrapper rather than to whichever `AutoreleasePool` happens to be on top, matching
+            // the hand-written wrappers in `src/lib.rs`.
+            let (args, call) = match method.kind {
+                MethodKind::Class => (
+                    extra_args.trim_start_matches(", ").to_string(),
+                    msg_send_call(&format!("objc::class!({name})"), method.selector, method.args),
+                ),
+                MethodKind::Instance => (
+                    format!("&self{extra_args}"),
+                    msg_send_call("self.0", method.selector, method.args),
+                ),
+            };
+
+            out.push_str(&format!(
+                "    /// `{selector}` returns an autoreleased {ret}, so it's retained on ingest; `Drop` releases it.\n    pub fn {rust_name}({args}) -> {ret} {{\n        let raw: id = unsafe {{ msg_send![{call}] }};\n        {ret}(unsafe {{ objc_retain(raw) }})\n    }}\n",
+                selector = method.selector,
+                ret = ret,
+                rust_name = rust_name,
+                args = args,
+                call = call,
+            ));
+            continue;
+        }
+
+        match method.kind {
+            MethodKind::Class => {
+                out.push_str(&format!(
+                    "    #[selector_export({class}, \"{selector}\")]\n    pub fn {rust_name}({args}) -> {ret};\n",
+                    class = name,
+                    selector = method.selector,
+                    rust_name = rust_name,
+                    args = extra_args.trim_start_matches(", "),
+                    ret = ret,
+                ));
+            }
+            MethodKind::Instance => {
+                out.push_str(&format!(
+                    "    #[selector_export(\"{selector}\")]\n    pub fn {rust_name}(&self{args}) -> {ret};\n",
+                    selector = method.selector,
+                    rust_name = rust_name,
+                    args = extra_args,
+                    ret = ret,
+                ));
+            }
+        }
+    }
+
+    out.push_str(&format!(
+        "}}\n\nimpl GetObjcObject for {name} {{\n    fn objc_object(&self) -> id {{\n        self.0\n    }}\n}}\n\n",
+        name = name
+    ));
+
+    out
+}
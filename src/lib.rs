@@ -1,4 +1,4 @@
-use std::{ffi::c_void, path::PathBuf, ptr::null_mut, slice};
+use std::{ffi::c_void, mem, path::PathBuf, ptr::null_mut, slice};
 
 use objc::{msg_send, runtime::Class, sel, sel_impl};
 use objc_derive::selector_export;
@@ -14,10 +14,60 @@ pub trait GetObjcObject {
 
 extern "C" {
     pub fn NSClassFromString(class_name: NSString) -> Class;
+
+    fn objc_autoreleasePoolPush() -> *mut c_void;
+    fn objc_autoreleasePoolPop(pool: *mut c_void);
+}
+
+/// Sends `retain` to a raw object pointer. Used by wrapper types to take ownership of an object
+/// handed back from an autoreleased class convenience constructor, so its lifetime is tied to the
+/// Rust wrapper rather than to whichever `AutoreleasePool` happens to be on top when it's created.
+unsafe fn objc_retain(obj: id) -> id {
+    msg_send![obj, retain]
+}
+
+/// Sends `release` to a raw object pointer. Balances either an owning `alloc`/`init` call or a
+/// prior [`objc_retain`], per the ownership rule documented on each wrapper's constructor.
+unsafe fn objc_release(obj: id) {
+    msg_send![obj, release]
+}
+
+/// Wrapper boilerplate for the classes listed in `codegen::MANIFEST` (see `build.rs`), generated at
+/// build time and gated behind the `codegen` feature so it never affects a default build. Extending
+/// Foundation coverage through the manifest avoids hand-writing another `#[repr(transparent)]` type
+/// for each new class.
+#[cfg(feature = "codegen")]
+include!(concat!(env!("OUT_DIR"), "/generated_bindings.rs"));
+
+/// RAII guard around an `objc_autoreleasePoolPush`/`objc_autoreleasePoolPop` scope, mirroring
+/// Objective-C's `@autoreleasepool { ... }` block. Autoreleased objects created while a guard is
+/// alive are drained when it is dropped, rather than accumulating for the lifetime of the thread.
+pub struct AutoreleasePool {
+    context: *mut c_void,
+}
+
+impl AutoreleasePool {
+    pub fn new() -> Self {
+        Self {
+            context: unsafe { objc_autoreleasePoolPush() },
+        }
+    }
+}
+
+impl Drop for AutoreleasePool {
+    fn drop(&mut self) {
+        unsafe { objc_autoreleasePoolPop(self.context) };
+    }
+}
+
+/// Runs `body` inside a fresh [`AutoreleasePool`], draining it as soon as `body` returns. Prefer
+/// this over a bare `AutoreleasePool::new()` binding for scoping a tight loop or a single call.
+pub fn pool<T, F: FnOnce() -> T>(body: F) -> T {
+    let _scope = AutoreleasePool::new();
+    body()
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSString(pub id);
 impl std::ops::Deref for NSString {
     type Target = objc::runtime::Object;
@@ -25,6 +75,18 @@ impl std::ops::Deref for NSString {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSString {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSString {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSString {}
 impl NSString {
     pub fn alloc() -> Self {
@@ -33,6 +95,8 @@ impl NSString {
 }
 
 impl NSString {
+    /// `alloc`/`init...` hands back an object with retain count 1 that we already own, so no
+    /// extra retain is needed here; `Drop` releases it.
     pub fn from_str(val: &str) -> Self {
         let alloc = NSString::alloc();
         unsafe {
@@ -41,6 +105,9 @@ impl NSString {
                 val.len() as usize,
                 4,
             );
+            // `init...` hands ownership of the (possibly replaced) instance to `ret`; forget the
+            // `alloc` handle so its `Drop` doesn't also release the same object.
+            mem::forget(alloc);
             ret
         }
     }
@@ -63,6 +130,84 @@ impl NSString {
     }
 }
 
+impl NSString {
+    /// `pathComponents` returns an autoreleased array, so it's retained on ingest; `Drop` releases
+    /// it.
+    pub fn path_components_array(&self) -> NSArray {
+        let raw: id = unsafe { msg_send![self.0, pathComponents] };
+        NSArray(unsafe { objc_retain(raw) })
+    }
+
+    /// `lastPathComponent` returns an autoreleased string, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn last_path_component(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, lastPathComponent] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+
+    /// `pathExtension` returns an autoreleased string, so it's retained on ingest; `Drop` releases
+    /// it.
+    pub fn path_extension(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, pathExtension] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+
+    /// `stringByDeletingLastPathComponent` returns an autoreleased string, so it's retained on
+    /// ingest; `Drop` releases it.
+    pub fn string_by_deleting_last_path_component(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, stringByDeletingLastPathComponent] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+
+    /// `stringByAppendingPathComponent:` returns an autoreleased string, so it's retained on
+    /// ingest; `Drop` releases it.
+    pub fn string_by_appending_path_component(&self, path_component: NSString) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, stringByAppendingPathComponent: path_component] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+
+    /// `stringByDeletingPathExtension` returns an autoreleased string, so it's retained on ingest;
+    /// `Drop` releases it.
+    pub fn string_by_deleting_path_extension(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, stringByDeletingPathExtension] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+
+    #[selector_export("isAbsolutePath")]
+    pub fn is_absolute_path(&self) -> bool;
+}
+
+impl NSString {
+    /// `pathWithComponents:` returns an autoreleased string, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn path_with_components_array(components: NSArray) -> NSString {
+        let raw: id = unsafe { msg_send![objc::class!(NSString), pathWithComponents: components] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+}
+
+impl NSString {
+    /// Splits this path into its individual components, e.g. `/tmp/foo.txt` becomes
+    /// `["/", "tmp", "foo.txt"]`, mirroring `NSString.pathComponents`.
+    pub fn path_components(&self) -> Vec<String> {
+        self.path_components_array().map(|val| {
+            // `val` is borrowed from the array, not owned by us; retain before wrapping it in a
+            // `Drop`-releasing `NSString` so we don't release memory the array still references.
+            let component = NSString(unsafe { objc_retain(val) });
+            component.to_string()
+        })
+    }
+
+    /// Builds a path `NSString` out of its components, applying Apple's path-joining rules
+    /// (e.g. collapsing duplicate slashes), mirroring `+[NSString pathWithComponents:]`.
+    pub fn path_with_components(components: &[&str]) -> NSString {
+        let strings: Vec<NSString> = components.iter().map(|c| NSString::from_str(c)).collect();
+        let ids: Vec<id> = strings.iter().map(|s| s.0).collect();
+        let array = NSArray::from_slice(&ids);
+        NSString::path_with_components_array(array)
+    }
+}
+
 impl NSString {
     #[selector_export("initWithBytes:length:encoding:")]
     pub fn init_with_bytes_length_encoding(&self, bytes: *const c_void, length: usize, encoding: u64) -> NSString;
@@ -81,7 +226,6 @@ impl GetObjcObject for NSString {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSSet(pub id);
 impl std::ops::Deref for NSSet {
     type Target = objc::runtime::Object;
@@ -89,6 +233,18 @@ impl std::ops::Deref for NSSet {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSSet {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSSet {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSSet {}
 impl NSSet {
     pub fn alloc() -> Self {
@@ -97,8 +253,12 @@ impl NSSet {
 }
 
 impl NSSet {
-    #[selector_export(NSSet, "setWithArray:")]
-    pub fn set_with_array(array: NSArray) -> NSSet;
+    /// `setWithArray:` returns an autoreleased set, so it's retained on ingest; `Drop` releases
+    /// it.
+    pub fn set_with_array(array: NSArray) -> NSSet {
+        let raw: id = unsafe { msg_send![objc::class!(NSSet), setWithArray: array] };
+        NSSet(unsafe { objc_retain(raw) })
+    }
 }
 
 impl GetObjcObject for NSSet {
@@ -108,7 +268,6 @@ impl GetObjcObject for NSSet {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSDictionary(pub id);
 impl std::ops::Deref for NSDictionary {
     type Target = objc::runtime::Object;
@@ -116,6 +275,18 @@ impl std::ops::Deref for NSDictionary {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSDictionary {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSDictionary {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSDictionary {}
 impl NSDictionary {
     pub fn alloc() -> Self {
@@ -124,8 +295,14 @@ impl NSDictionary {
 }
 
 impl NSDictionary {
-    #[selector_export("init")]
-    pub fn init(&self) -> NSDictionary;
+    /// Called on an `alloc`'d instance. `init` may return a different pointer than it was sent
+    /// to, so this consumes `self` and forgets it rather than borrowing, so its `Drop` doesn't
+    /// release the object the returned instance now owns.
+    pub fn init(self) -> NSDictionary {
+        let ret = NSDictionary(unsafe { msg_send![self.0, init] });
+        mem::forget(self);
+        ret
+    }
 }
 
 impl GetObjcObject for NSDictionary {
@@ -135,7 +312,6 @@ impl GetObjcObject for NSDictionary {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSArray(pub id);
 impl std::ops::Deref for NSArray {
     type Target = objc::runtime::Object;
@@ -143,6 +319,18 @@ impl std::ops::Deref for NSArray {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSArray {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSArray {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSArray {}
 impl NSArray {
     pub fn alloc() -> Self {
@@ -182,8 +370,12 @@ impl NSArray {
 }
 
 impl NSArray {
-    #[selector_export(NSArray, "arrayWithObjects:count:")]
-    pub fn array_with_objects_count(objects: *mut id, count: u64) -> NSArray;
+    /// `arrayWithObjects:count:` returns an autoreleased array, so it's retained on ingest;
+    /// `Drop` releases it.
+    pub fn array_with_objects_count(objects: *mut id, count: u64) -> NSArray {
+        let raw: id = unsafe { msg_send![objc::class!(NSArray), arrayWithObjects: objects count: count] };
+        NSArray(unsafe { objc_retain(raw) })
+    }
 
     #[selector_export("count")]
     pub fn count(&self) -> usize;
@@ -199,7 +391,6 @@ impl GetObjcObject for NSArray {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSDecimalNumber(pub id);
 impl std::ops::Deref for NSDecimalNumber {
     type Target = objc::runtime::Object;
@@ -207,6 +398,18 @@ impl std::ops::Deref for NSDecimalNumber {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSDecimalNumber {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSDecimalNumber {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSDecimalNumber {}
 impl NSDecimalNumber {
     pub fn alloc() -> Self {
@@ -226,7 +429,6 @@ impl GetObjcObject for NSDecimalNumber {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSLocale(pub id);
 impl std::ops::Deref for NSLocale {
     type Target = objc::runtime::Object;
@@ -234,6 +436,18 @@ impl std::ops::Deref for NSLocale {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSLocale {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSLocale {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSLocale {}
 impl NSLocale {
     pub fn alloc() -> Self {
@@ -242,7 +456,6 @@ impl NSLocale {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSNumber(pub id);
 impl std::ops::Deref for NSNumber {
     type Target = objc::runtime::Object;
@@ -250,6 +463,18 @@ impl std::ops::Deref for NSNumber {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSNumber {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSNumber {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSNumber {}
 impl NSNumber {
     pub fn alloc() -> Self {
@@ -257,8 +482,144 @@ impl NSNumber {
     }
 }
 
+impl NSNumber {
+    /// `numberWithDouble:`, `numberWithInteger:` and `numberWithBool:` all return an autoreleased
+    /// number, so each is retained on ingest; `Drop` releases it.
+    pub fn number_with_double(value: f64) -> NSNumber {
+        let raw: id = unsafe { msg_send![objc::class!(NSNumber), numberWithDouble: value] };
+        NSNumber(unsafe { objc_retain(raw) })
+    }
+
+    pub fn number_with_integer(value: NSInteger) -> NSNumber {
+        let raw: id = unsafe { msg_send![objc::class!(NSNumber), numberWithInteger: value] };
+        NSNumber(unsafe { objc_retain(raw) })
+    }
+
+    pub fn number_with_bool(value: bool) -> NSNumber {
+        let raw: id = unsafe { msg_send![objc::class!(NSNumber), numberWithBool: value] };
+        NSNumber(unsafe { objc_retain(raw) })
+    }
+
+    #[selector_export("doubleValue")]
+    pub fn double_value(&self) -> f64;
+
+    #[selector_export("integerValue")]
+    pub fn integer_value(&self) -> NSInteger;
+
+    #[selector_export("boolValue")]
+    pub fn bool_value(&self) -> bool;
+}
+
+impl GetObjcObject for NSNumber {
+    fn objc_object(&self) -> id {
+        self.0
+    }
+}
+
+// CGFloat is a typedef for `f64` on every target this crate supports (all 64-bit).
+pub type CGFloat = f64;
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NSPoint {
+    pub x: CGFloat,
+    pub y: CGFloat,
+}
+
+unsafe impl objc::Encode for NSPoint {
+    fn encode() -> objc::Encoding {
+        unsafe { objc::Encoding::from_str("{CGPoint=dd}") }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NSSize {
+    pub width: CGFloat,
+    pub height: CGFloat,
+}
+
+unsafe impl objc::Encode for NSSize {
+    fn encode() -> objc::Encoding {
+        unsafe { objc::Encoding::from_str("{CGSize=dd}") }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NSRect {
+    pub origin: NSPoint,
+    pub size: NSSize,
+}
+
+unsafe impl objc::Encode for NSRect {
+    fn encode() -> objc::Encoding {
+        unsafe { objc::Encoding::from_str("{CGRect={CGPoint=dd}{CGSize=dd}}") }
+    }
+}
+
+#[repr(transparent)]
+pub struct NSValue(pub id);
+impl std::ops::Deref for NSValue {
+    type Target = objc::runtime::Object;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &*self.0 }
+    }
+}
+
+impl Drop for NSValue {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSValue {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
+unsafe impl objc::Message for NSValue {}
+impl NSValue {
+    pub fn alloc() -> Self {
+        Self(unsafe { msg_send!(objc::class!(NSValue), alloc) })
+    }
+}
+
+impl NSValue {
+    /// `valueWithPoint:`, `valueWithSize:` and `valueWithRect:` all return an autoreleased value,
+    /// so each is retained on ingest; `Drop` releases it.
+    pub fn value_with_point(point: NSPoint) -> NSValue {
+        let raw: id = unsafe { msg_send![objc::class!(NSValue), valueWithPoint: point] };
+        NSValue(unsafe { objc_retain(raw) })
+    }
+
+    pub fn value_with_size(size: NSSize) -> NSValue {
+        let raw: id = unsafe { msg_send![objc::class!(NSValue), valueWithSize: size] };
+        NSValue(unsafe { objc_retain(raw) })
+    }
+
+    pub fn value_with_rect(rect: NSRect) -> NSValue {
+        let raw: id = unsafe { msg_send![objc::class!(NSValue), valueWithRect: rect] };
+        NSValue(unsafe { objc_retain(raw) })
+    }
+
+    #[selector_export("pointValue")]
+    pub fn point_value(&self) -> NSPoint;
+
+    #[selector_export("sizeValue")]
+    pub fn size_value(&self) -> NSSize;
+
+    #[selector_export("rectValue")]
+    pub fn rect_value(&self) -> NSRect;
+}
+
+impl GetObjcObject for NSValue {
+    fn objc_object(&self) -> id {
+        self.0
+    }
+}
+
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSNumberFormatter(pub id);
 impl std::ops::Deref for NSNumberFormatter {
     type Target = objc::runtime::Object;
@@ -266,6 +627,18 @@ impl std::ops::Deref for NSNumberFormatter {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSNumberFormatter {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSNumberFormatter {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSNumberFormatter {}
 impl NSNumberFormatter {
     pub fn alloc() -> Self {
@@ -283,8 +656,12 @@ impl NSNumberFormatter {
     #[selector_export("setLocale:")]
     pub fn set_locale(&self, value: NSLocale);
 
-    #[selector_export("stringFromNumber:")]
-    pub fn string_from_number(&self, number: NSNumber) -> NSString;
+    /// `stringFromNumber:` returns an autoreleased string, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn string_from_number(&self, number: NSNumber) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, stringFromNumber: number] };
+        NSString(unsafe { objc_retain(raw) })
+    }
 }
 
 impl GetObjcObject for NSNumberFormatter {
@@ -294,7 +671,6 @@ impl GetObjcObject for NSNumberFormatter {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSURL(pub id);
 impl std::ops::Deref for NSURL {
     type Target = objc::runtime::Object;
@@ -302,6 +678,18 @@ impl std::ops::Deref for NSURL {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSURL {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSURL {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSURL {}
 impl NSURL {
     pub fn alloc() -> Self {
@@ -310,17 +698,30 @@ impl NSURL {
 }
 
 impl NSURL {
-    #[selector_export(NSURL, "URLWithString:")]
-    pub fn url_with_string(url_string: NSString) -> NSURL;
+    /// `URLWithString:` and `fileURLWithPath:` both return an autoreleased URL, so each is
+    /// retained on ingest; `Drop` releases it.
+    pub fn url_with_string(url_string: NSString) -> NSURL {
+        let raw: id = unsafe { msg_send![objc::class!(NSURL), URLWithString: url_string] };
+        NSURL(unsafe { objc_retain(raw) })
+    }
+
+    pub fn file_url_with_path(path: NSString) -> NSURL {
+        let raw: id = unsafe { msg_send![objc::class!(NSURL), fileURLWithPath: path] };
+        NSURL(unsafe { objc_retain(raw) })
+    }
 
-    #[selector_export(NSURL, "fileURLWithPath:")]
-    pub fn file_url_with_path(path: NSString) -> NSURL;
-    
-    #[selector_export("absoluteString")]
-    pub fn absolute_string(&self) -> NSString;
+    /// `absoluteString` returns an autoreleased string, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn absolute_string(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, absoluteString] };
+        NSString(unsafe { objc_retain(raw) })
+    }
 
-    #[selector_export("path")]
-    pub fn path(&self) -> NSString;
+    /// `path` returns an autoreleased string, so it's retained on ingest; `Drop` releases it.
+    pub fn path(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, path] };
+        NSString(unsafe { objc_retain(raw) })
+    }
 
     #[selector_export("startAccessingSecurityScopedResource")]
     pub fn start_accessing_security_scoped_resource(&self) -> bool;
@@ -334,7 +735,6 @@ impl GetObjcObject for NSURL {
 
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct UTType(pub id);
 impl std::ops::Deref for UTType {
     type Target = objc::runtime::Object;
@@ -342,6 +742,18 @@ impl std::ops::Deref for UTType {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for UTType {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for UTType {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for UTType {}
 impl UTType {
     pub fn alloc() -> Self {
@@ -350,8 +762,12 @@ impl UTType {
 }
 
 impl UTType {
-    #[selector_export(UTType, "typeWithFilenameExtension:")]
-    pub fn type_with_filename_extension(filenameExtension: NSString) -> UTType;
+    /// `typeWithFilenameExtension:` returns an autoreleased type, so it's retained on ingest;
+    /// `Drop` releases it.
+    pub fn type_with_filename_extension(filenameExtension: NSString) -> UTType {
+        let raw: id = unsafe { msg_send![objc::class!(UTType), typeWithFilenameExtension: filenameExtension] };
+        UTType(unsafe { objc_retain(raw) })
+    }
 }
 
 impl GetObjcObject for UTType {
@@ -361,7 +777,6 @@ impl GetObjcObject for UTType {
 }
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSOperationQueue(pub id);
 impl std::ops::Deref for NSOperationQueue {
     type Target = objc::runtime::Object;
@@ -369,6 +784,18 @@ impl std::ops::Deref for NSOperationQueue {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSOperationQueue {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSOperationQueue {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSOperationQueue {}
 impl NSOperationQueue {
     pub fn alloc() -> Self {
@@ -377,8 +804,12 @@ impl NSOperationQueue {
 }
 
 impl NSOperationQueue {
-    #[selector_export(NSOperationQueue, "mainQueue")]
-    pub fn main_queue() -> NSOperationQueue;
+    /// `mainQueue` returns the autoreleased shared queue, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn main_queue() -> NSOperationQueue {
+        let raw: id = unsafe { msg_send![objc::class!(NSOperationQueue), mainQueue] };
+        NSOperationQueue(unsafe { objc_retain(raw) })
+    }
 
     #[selector_export("addOperationWithBlock:")]
     pub fn add_operation_with_block(&self, block: *const ::block::Block<(), ()>);
@@ -392,7 +823,6 @@ impl GetObjcObject for NSOperationQueue {
 
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSUserDefaults(pub id);
 impl std::ops::Deref for NSUserDefaults {
     type Target = objc::runtime::Object;
@@ -400,6 +830,18 @@ impl std::ops::Deref for NSUserDefaults {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSUserDefaults {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSUserDefaults {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSUserDefaults {}
 impl NSUserDefaults {
     pub fn alloc() -> Self {
@@ -408,14 +850,22 @@ impl NSUserDefaults {
 }
 
 impl NSUserDefaults {
-    #[selector_export(NSUserDefaults, "standardUserDefaults")]
-    pub fn standard_user_defaults() -> NSUserDefaults;
+    /// `standardUserDefaults` returns the autoreleased shared defaults, so it's retained on
+    /// ingest; `Drop` releases it.
+    pub fn standard_user_defaults() -> NSUserDefaults {
+        let raw: id = unsafe { msg_send![objc::class!(NSUserDefaults), standardUserDefaults] };
+        NSUserDefaults(unsafe { objc_retain(raw) })
+    }
 
     #[selector_export("objectForKey:")]
     pub fn object_for_key(&self, key: NSString) -> id;
 
-    #[selector_export("stringForKey:")]
-    pub fn string_for_key(&self, key: NSString) -> NSString;
+    /// `stringForKey:` returns an autoreleased string, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn string_for_key(&self, key: NSString) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, stringForKey: key] };
+        NSString(unsafe { objc_retain(raw) })
+    }
 
     #[selector_export("integerForKey:")]
     pub fn integer_for_key(&self, key: NSString) -> NSInteger;
@@ -446,7 +896,6 @@ impl GetObjcObject for NSUserDefaults {
 
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSError(pub id);
 impl std::ops::Deref for NSError {
     type Target = objc::runtime::Object;
@@ -454,6 +903,18 @@ impl std::ops::Deref for NSError {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSError {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSError {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSError {}
 impl NSError {
     pub fn alloc() -> Self {
@@ -465,8 +926,12 @@ impl NSError {
     #[selector_export("code")]
     pub fn code(&self) -> NSInteger;
 
-    #[selector_export("localizedDescription")]
-    pub fn localized_description(&self) -> NSString;
+    /// `localizedDescription` returns an autoreleased string, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn localized_description(&self) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, localizedDescription] };
+        NSString(unsafe { objc_retain(raw) })
+    }
 }
 
 impl GetObjcObject for NSError {
@@ -475,10 +940,23 @@ impl GetObjcObject for NSError {
     }
 }
 
+/// Calls `body` with a pointer to an out-parameter slot intended for an `NSError **`, then turns
+/// the Cocoa "null on success, populated pointer on failure" convention into an idiomatic
+/// `Result`. `body` should pass the pointer straight through to the trailing `error:` argument of
+/// the selector it invokes. The `NSError` picked up this way is autoreleased by the method that
+/// populated it, so it's retained on ingest; `Drop` releases it.
+pub fn with_error_out<T>(body: impl FnOnce(*mut id) -> T) -> Result<T, NSError> {
+    let mut err: id = null_mut();
+    let ret = body(&mut err as *mut id);
 
+    if err.is_null() {
+        Ok(ret)
+    } else {
+        Err(NSError(unsafe { objc_retain(err) }))
+    }
+}
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSData(pub id);
 impl std::ops::Deref for NSData {
     type Target = objc::runtime::Object;
@@ -486,6 +964,18 @@ impl std::ops::Deref for NSData {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSData {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSData {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSData {}
 impl NSData {
     pub fn alloc() -> Self {
@@ -494,11 +984,84 @@ impl NSData {
 }
 
 impl NSData {
-    #[selector_export("initWithContentsOfURL:")]
-    pub fn init_with_contents_of_url(&self, url: NSURL) -> NSData;
+    /// Called on an `alloc`'d instance. `init...` may return a different pointer than it was
+    /// sent to, so this consumes `self` and forgets it rather than borrowing, so its `Drop`
+    /// doesn't release the object the returned instance now owns.
+    pub fn init_with_contents_of_url(self, url: NSURL) -> NSData {
+        let ret = NSData(unsafe { msg_send![self.0, initWithContentsOfURL: url] });
+        mem::forget(self);
+        ret
+    }
+
+    /// `base64EncodedStringWithOptions:` returns an autoreleased string, so it's retained on
+    /// ingest; `Drop` releases it.
+    pub fn base64_encoded_string_with_options(&self, options: NSDataBase64EncodingOptions) -> NSString {
+        let raw: id = unsafe { msg_send![self.0, base64EncodedStringWithOptions: options] };
+        NSString(unsafe { objc_retain(raw) })
+    }
+
+    /// Called on an `alloc`'d instance. `init...` may return a different pointer than it was
+    /// sent to, so this consumes `self` and forgets it rather than borrowing, so its `Drop`
+    /// doesn't release the object the returned instance now owns.
+    pub fn init_with_base64_encoded_string_options(
+        self,
+        base64_string: NSString,
+        options: NSDataBase64DecodingOptions,
+    ) -> NSData {
+        let ret = NSData(unsafe {
+            msg_send![self.0, initWithBase64EncodedString: base64_string options: options]
+        });
+        mem::forget(self);
+        ret
+    }
+
+    /// Called on an `alloc`'d instance. `init...` may return a different pointer than it was
+    /// sent to, so this consumes `self` and forgets it rather than borrowing, so its `Drop`
+    /// doesn't release the object the returned instance now owns.
+    pub fn init_with_bytes_length(self, bytes: *const c_void, length: usize) -> NSData {
+        let ret = NSData(unsafe { msg_send![self.0, initWithBytes: bytes length: length] });
+        mem::forget(self);
+        ret
+    }
+
+    #[selector_export("bytes")]
+    pub fn bytes(&self) -> *const u8;
+
+    #[selector_export("length")]
+    pub fn length(&self) -> usize;
+}
+
+impl NSData {
+    /// A utility method for taking an `NSData` and bridging it to a Rust `&[u8]`.
+    pub fn to_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.bytes(), self.length()) }
+    }
 
-    #[selector_export("base64EncodedStringWithOptions:")]
-    pub fn base64_encoded_string_with_options(&self, options: NSUInteger) -> NSString;
+    /// Fallible wrapper around `writeToURL:options:error:`.
+    pub fn write_to_url_options_error(&self, url: NSURL, options: NSUInteger) -> Result<(), NSError> {
+        with_error_out(|err_ptr| unsafe {
+            let _: bool = msg_send![self.0, writeToURL: url options: options error: err_ptr];
+        })
+    }
+}
+
+impl NSData {
+    /// Fallible counterpart to [`NSData::init_with_contents_of_url`], surfacing
+    /// `initWithContentsOfURL:options:error:`'s `NSError **` out-parameter as a `Result`.
+    pub fn init_with_contents_of_url_options_error(
+        url: NSURL,
+        options: NSUInteger,
+    ) -> Result<NSData, NSError> {
+        let alloc = NSData::alloc();
+
+        with_error_out(|err_ptr| unsafe {
+            let raw: id = msg_send![alloc.0, initWithContentsOfURL: url options: options error: err_ptr];
+            // `init...` hands ownership of the (possibly replaced) instance to `raw`; forget the
+            // `alloc` handle so its `Drop` doesn't also release the same object.
+            mem::forget(alloc);
+            NSData(raw)
+        })
+    }
 }
 
 impl GetObjcObject for NSData {
@@ -509,7 +1072,6 @@ impl GetObjcObject for NSData {
 
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSBundle(pub id);
 impl std::ops::Deref for NSBundle {
     type Target = objc::runtime::Object;
@@ -517,6 +1079,18 @@ impl std::ops::Deref for NSBundle {
         unsafe { &*self.0 }
     }
 }
+
+impl Drop for NSBundle {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSBundle {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
 unsafe impl objc::Message for NSBundle {}
 impl NSBundle {
     pub fn alloc() -> Self {
@@ -525,11 +1099,19 @@ impl NSBundle {
 }
 
 impl NSBundle {
-    #[selector_export(NSBundle, "mainBundle")]
-    pub fn main_bundle() -> NSBundle;
+    /// `mainBundle` returns the autoreleased shared bundle, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn main_bundle() -> NSBundle {
+        let raw: id = unsafe { msg_send![objc::class!(NSBundle), mainBundle] };
+        NSBundle(unsafe { objc_retain(raw) })
+    }
 
-    #[selector_export("appStoreReceiptURL")]
-    pub fn app_store_receipt_url(&self) -> NSURL;
+    /// `appStoreReceiptURL` returns an autoreleased URL, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn app_store_receipt_url(&self) -> NSURL {
+        let raw: id = unsafe { msg_send![self.0, appStoreReceiptURL] };
+        NSURL(unsafe { objc_retain(raw) })
+    }
 }
 
 impl GetObjcObject for NSBundle {
@@ -540,7 +1122,6 @@ impl GetObjcObject for NSBundle {
 
 
 #[repr(transparent)]
-#[derive(Clone)]
 pub struct NSFileManager(pub id);
 impl std::ops::Deref for NSFileManager {
     type Target = objc::runtime::Object;
@@ -549,22 +1130,143 @@ impl std::ops::Deref for NSFileManager {
     }
 }
 
+impl Drop for NSFileManager {
+    fn drop(&mut self) {
+        unsafe { objc_release(self.0) };
+    }
+}
+
+impl Clone for NSFileManager {
+    fn clone(&self) -> Self {
+        Self(unsafe { objc_retain(self.0) })
+    }
+}
+
 impl NSFileManager {
-    #[selector_export(NSFileManager, "defaultManager")]
-    pub fn default_manager() -> NSFileManager;
+    /// `defaultManager` returns the autoreleased shared manager, so it's retained on ingest;
+    /// `Drop` releases it.
+    pub fn default_manager() -> NSFileManager {
+        let raw: id = unsafe { msg_send![objc::class!(NSFileManager), defaultManager] };
+        NSFileManager(unsafe { objc_retain(raw) })
+    }
 
-    #[selector_export("temporaryDirectory")]
-    pub fn temporary_directory(&self) -> NSURL;
+    /// `temporaryDirectory` returns an autoreleased URL, so it's retained on ingest; `Drop`
+    /// releases it.
+    pub fn temporary_directory(&self) -> NSURL {
+        let raw: id = unsafe { msg_send![self.0, temporaryDirectory] };
+        NSURL(unsafe { objc_retain(raw) })
+    }
 
-    #[selector_export("URLsForDirectory:inDomains:")]
-    pub fn urls_for_directory_in_domains(&self, directory: NSSearchPathDirectory, domain_mask: NSSearchPathDomainMask) -> NSArray;
+    /// `URLsForDirectory:inDomains:` returns an autoreleased array, so it's retained on ingest;
+    /// `Drop` releases it.
+    pub fn urls_for_directory_in_domains(&self, directory: NSSearchPathDirectory, domain_mask: NSSearchPathDomainMask) -> NSArray {
+        let raw: id = unsafe { msg_send![self.0, URLsForDirectory: directory inDomains: domain_mask] };
+        NSArray(unsafe { objc_retain(raw) })
+    }
 }
 
 impl NSFileManager {
+    /// Fallible wrapper around `contentsOfDirectoryAtURL:includingPropertiesForKeys:options:error:`.
+    pub fn contents_of_directory_at_url_including_keys_options_error(
+        &self,
+        url: NSURL,
+        keys: NSArray,
+        options: NSDirectoryEnumerationOptions,
+    ) -> Result<Vec<NSURL>, NSError> {
+        with_error_out(|err_ptr| unsafe {
+            let raw: id = msg_send![
+                self.0,
+                contentsOfDirectoryAtURL: url
+                includingPropertiesForKeys: keys
+                options: options
+                error: err_ptr
+            ];
+            NSArray(objc_retain(raw))
+        })
+        .map(|array| {
+            array.map(|val| {
+                // `val` is borrowed from the array, not owned by us; retain before wrapping it in
+                // a `Drop`-releasing `NSURL` so we don't release memory the array still references.
+                NSURL(unsafe { objc_retain(val) })
+            })
+        })
+    }
+
+    /// Fallible wrapper around `createDirectoryAtURL:withIntermediateDirectories:attributes:error:`.
+    pub fn create_directory_at_url_with_intermediate_directories_attributes_error(
+        &self,
+        url: NSURL,
+        create_intermediate_directories: bool,
+        attributes: NSDictionary,
+    ) -> Result<(), NSError> {
+        with_error_out(|err_ptr| unsafe {
+            let _: bool = msg_send![
+                self.0,
+                createDirectoryAtURL: url
+                withIntermediateDirectories: create_intermediate_directories
+                attributes: attributes
+                error: err_ptr
+            ];
+        })
+    }
+
+    /// Fallible wrapper around `URLForDirectory:inDomain:appropriateForURL:create:error:` — the
+    /// preferred replacement for `URLsForDirectory:inDomains:` when you need the item-replacement
+    /// directory for a specific URL (e.g. for safe-save).
+    pub fn url_for_directory_in_domain_appropriate_for_url_create_error(
+        &self,
+        directory: NSSearchPathDirectory,
+        domain: NSSearchPathDomainMask,
+        appropriate_for_url: NSURL,
+        should_create: bool,
+    ) -> Result<NSURL, NSError> {
+        with_error_out(|err_ptr| unsafe {
+            let raw: id = msg_send![
+                self.0,
+                URLForDirectory: directory
+                inDomain: domain
+                appropriateForURL: appropriate_for_url
+                create: should_create
+                error: err_ptr
+            ];
+            NSURL(objc_retain(raw))
+        })
+    }
+
+    pub fn file_exists_at_path(&self, path: &str) -> bool {
+        let path = NSString::from_str(path);
+        unsafe { msg_send![self.0, fileExistsAtPath: path] }
+    }
+
+    pub fn file_exists_at_path_is_directory(&self, path: &str) -> (bool, bool) {
+        let path = NSString::from_str(path);
+        let mut is_directory = false;
+        let exists: bool = unsafe {
+            msg_send![self.0, fileExistsAtPath: path isDirectory: &mut is_directory as *mut bool]
+        };
+        (exists, is_directory)
+    }
+
+    /// Fallible wrapper around `removeItemAtURL:error:`.
+    pub fn remove_item_at_url_error(&self, url: NSURL) -> Result<(), NSError> {
+        with_error_out(|err_ptr| unsafe {
+            let _: bool = msg_send![self.0, removeItemAtURL: url error: err_ptr];
+        })
+    }
+
+    /// Fallible wrapper around `moveItemAtURL:toURL:error:`.
+    pub fn move_item_at_url_to_url_error(&self, src_url: NSURL, dst_url: NSURL) -> Result<(), NSError> {
+        with_error_out(|err_ptr| unsafe {
+            let _: bool = msg_send![self.0, moveItemAtURL: src_url toURL: dst_url error: err_ptr];
+        })
+    }
+
     pub fn get_documents_dir(&self) -> PathBuf {
         let paths = self.urls_for_directory_in_domains(NSSearchPathDirectory_NSDocumentDirectory, NSSearchPathDomainMask_NSUserDomainMask);
         let urls = paths.map(|val| {
-            NSURL(val)
+            // `val` is borrowed from the array, not owned by us; retain before wrapping it in a
+            // `Drop`-releasing `NSURL` so we don't release memory the array still references.
+            NSURL(unsafe { objc_retain(val) })
         });
         let path_string = urls[0].path().to_string();
         PathBuf::from(&path_string)
@@ -610,4 +1312,24 @@ pub const NSSearchPathDomainMask_NSLocalDomainMask: NSSearchPathDomainMask = 2;
 pub const NSSearchPathDomainMask_NSNetworkDomainMask: NSSearchPathDomainMask = 4;
 pub const NSSearchPathDomainMask_NSSystemDomainMask: NSSearchPathDomainMask = 8;
 pub const NSSearchPathDomainMask_NSAllDomainsMask: NSSearchPathDomainMask = 65535;
-pub type NSSearchPathDomainMask = NSUInteger;
\ No newline at end of file
+pub type NSSearchPathDomainMask = NSUInteger;
+
+pub const NSDirectoryEnumerationOptions_NSDirectoryEnumerationSkipsSubdirectoryDescendants: NSDirectoryEnumerationOptions = 1 << 0;
+pub const NSDirectoryEnumerationOptions_NSDirectoryEnumerationSkipsPackageDescendants: NSDirectoryEnumerationOptions = 1 << 1;
+pub const NSDirectoryEnumerationOptions_NSDirectoryEnumerationSkipsHiddenFiles: NSDirectoryEnumerationOptions = 1 << 2;
+pub const NSDirectoryEnumerationOptions_NSDirectoryEnumerationIncludesDirectoriesPostOrder: NSDirectoryEnumerationOptions = 1 << 3;
+pub const NSDirectoryEnumerationOptions_NSDirectoryEnumerationProducesRelativePathURLs: NSDirectoryEnumerationOptions = 1 << 4;
+pub type NSDirectoryEnumerationOptions = NSUInteger;
+
+pub const NSVolumeEnumerationOptions_NSVolumeEnumerationSkipHiddenVolumes: NSVolumeEnumerationOptions = 1 << 1;
+pub const NSVolumeEnumerationOptions_NSVolumeEnumerationProduceFileReferenceURLs: NSVolumeEnumerationOptions = 1 << 2;
+pub type NSVolumeEnumerationOptions = NSUInteger;
+
+pub const NSDataBase64DecodingOptions_NSDataBase64DecodingIgnoreUnknownCharacters: NSDataBase64DecodingOptions = 1 << 0;
+pub type NSDataBase64DecodingOptions = NSUInteger;
+
+pub const NSDataBase64EncodingOptions_NSDataBase64Encoding64CharacterLineLength: NSDataBase64EncodingOptions = 1 << 0;
+pub const NSDataBase64EncodingOptions_NSDataBase64Encoding76CharacterLineLength: NSDataBase64EncodingOptions = 1 << 1;
+pub const NSDataBase64EncodingOptions_NSDataBase64EncodingEndLineWithCarriageReturn: NSDataBase64EncodingOptions = 1 << 4;
+pub const NSDataBase64EncodingOptions_NSDataBase64EncodingEndLineWithLineFeed: NSDataBase64EncodingOptions = 1 << 5;
+pub type NSDataBase64EncodingOptions = NSUInteger;
\ No newline at end of file